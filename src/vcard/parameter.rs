@@ -0,0 +1,72 @@
+//! Property parameters (the `NAME=VALUE` fragments between the property type and the value, e.g.
+//! `TYPE=HOME` in `ADR;TYPE=HOME:...`).
+
+use std::fmt::{Display, Formatter};
+
+use crate::vcard::property::types::PropertyType;
+use crate::VcardError;
+
+/// A single `NAME=VALUE` property parameter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Parameter {
+    name: String,
+    value: String,
+}
+
+impl Parameter {
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+
+    /// Parses the `;`-separated parameter section of a property line (the text between the
+    /// property type and the value) into its individual parameters. `None` (no parameter section)
+    /// yields no parameters.
+    pub fn build_parameters(_property_type: &PropertyType, raw: Option<&str>) -> Result<Vec<Parameter>, VcardError> {
+        let Some(raw) = raw else {
+            return Ok(Vec::new());
+        };
+        raw.split(';')
+            .map(|fragment| {
+                fragment
+                    .split_once('=')
+                    .map(|(name, value)| Parameter { name: name.to_string(), value: value.to_string() })
+                    .ok_or_else(|| VcardError::PropertyMalformedString(fragment.to_string()))
+            })
+            .collect()
+    }
+}
+
+impl Display for Parameter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.name, self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vcard::parameter::Parameter;
+    use crate::vcard::property::types::PropertyType;
+
+    #[test]
+    pub fn build_parameters_parses_each_fragment() {
+        let parameters = Parameter::build_parameters(&PropertyType::Adr, Some("TYPE=HOME;PREF=1")).unwrap();
+
+        assert_eq!(parameters.len(), 2);
+        assert_eq!(parameters[0].to_string(), "TYPE=HOME");
+        assert_eq!(parameters[1].to_string(), "PREF=1");
+    }
+
+    #[test]
+    pub fn build_parameters_rejects_fragments_without_equals() {
+        assert!(Parameter::build_parameters(&PropertyType::Adr, Some("HOME")).is_err());
+    }
+
+    #[test]
+    pub fn build_parameters_none_yields_no_parameters() {
+        assert!(Parameter::build_parameters(&PropertyType::Adr, None).unwrap().is_empty());
+    }
+}
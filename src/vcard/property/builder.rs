@@ -0,0 +1,114 @@
+//! A fluent builder for [`Property`], for callers who don't want to hand-assemble RFC fragments
+//! via `TryFrom<(&PropertyType, &str, Option<Uuid>)>`. Validation is deferred to
+//! [`PropertyBuilder::build`], which reuses the same `Parameter`/`Value` validation as the rest of
+//! the crate.
+
+use uuid::Uuid;
+
+use crate::vcard::parameter::Parameter;
+use crate::vcard::property::types::PropertyType;
+use crate::vcard::property::Property;
+use crate::VcardError;
+
+/// Accumulates a value, parameters and an optional uuid for a [`PropertyType`], without
+/// validating until [`PropertyBuilder::build`] is called.
+pub struct PropertyBuilder {
+    property_type: PropertyType,
+    value: String,
+    parameters: Vec<String>,
+    uuid: Option<Uuid>,
+}
+
+impl PropertyBuilder {
+    /// Starts building a property of the given type, with an empty value and no parameters.
+    pub fn new(property_type: PropertyType) -> Self {
+        Self {
+            property_type,
+            value: String::new(),
+            parameters: Vec::new(),
+            uuid: None,
+        }
+    }
+
+    /// Sets the property's raw value.
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = value.to_string();
+        self
+    }
+
+    /// Appends an already-built parameter.
+    pub fn param(mut self, parameter: Parameter) -> Self {
+        self.parameters.push(parameter.to_string());
+        self
+    }
+
+    /// Appends a `PREF=<n>` parameter.
+    pub fn pref(mut self, pref: u8) -> Self {
+        self.parameters.push(format!("PREF={}", pref));
+        self
+    }
+
+    /// Appends a `TYPE=<name>` parameter.
+    pub fn type_(mut self, name: &str) -> Self {
+        self.parameters.push(format!("TYPE={}", name));
+        self
+    }
+
+    /// Sets the uuid the built property should carry, instead of generating a new one.
+    pub fn uuid(mut self, uuid: Uuid) -> Self {
+        self.uuid = Some(uuid);
+        self
+    }
+
+    /// Validates the accumulated value and parameters and builds the [`Property`].
+    pub fn build(self) -> Result<Property, VcardError> {
+        // Build the full "TYPE;PARAMS:VALUE" line directly and parse it with the `&str` overload.
+        // Going through `TryFrom<(&PropertyType, &str, Option<Uuid>)>` instead would prepend
+        // "TYPE:" in front of our already-assembled "PARAMS;VALUE" string, so the params would
+        // land after the colon and get parsed as part of the value instead of as parameters.
+        let str = if self.parameters.is_empty() {
+            format!("{}:{}", self.property_type, self.value)
+        } else {
+            format!("{};{}:{}", self.property_type, self.parameters.join(";"), self.value)
+        };
+        Property::try_from((str.as_str(), self.uuid))
+    }
+}
+
+impl Property {
+    /// Starts a [`PropertyBuilder`] for the given property type.
+    pub fn builder(property_type: PropertyType) -> PropertyBuilder {
+        PropertyBuilder::new(property_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vcard::property::types::PropertyType;
+    use crate::vcard::property::Property;
+
+    #[test]
+    pub fn builder_attaches_parameters_and_value() {
+        let property = Property::builder(PropertyType::Email).value("john@example.com").type_("HOME").pref(1).build().unwrap();
+
+        assert_eq!(property.get_parameters().len(), 2);
+        assert_eq!(property.get_value().to_string(), "john@example.com");
+        assert_eq!(property.to_string(), "EMAIL;TYPE=HOME;PREF=1:john@example.com");
+    }
+
+    #[test]
+    pub fn builder_without_parameters() {
+        let property = Property::builder(PropertyType::Fn).value("John Doe").build().unwrap();
+
+        assert!(property.get_parameters().is_empty());
+        assert_eq!(property.to_string(), "FN:John Doe");
+    }
+
+    #[test]
+    pub fn builder_keeps_requested_uuid() {
+        let uuid = uuid::Uuid::new_v4();
+        let property = Property::builder(PropertyType::Fn).value("John Doe").uuid(uuid).build().unwrap();
+
+        assert_eq!(property.get_uuid(), uuid);
+    }
+}
@@ -0,0 +1,138 @@
+//! RFC 6350 `DATE`/`DATE-TIME` parsing for properties such as `BDAY`, `ANNIVERSARY`, `REV` and
+//! `DEATHDATE`, gated behind the `timeconversions` feature. Truncated forms (`--MMDD`, `--MM`,
+//! `---DD`) omit the year and cannot map to a complete date, so they return an error rather than
+//! guessing one.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+
+use crate::vcard::property::Property;
+use crate::VcardError;
+
+impl Property {
+    /// Parses this property's value as an RFC 6350 date and returns it as a [`NaiveDate`].
+    pub fn as_date(&self) -> Result<NaiveDate, VcardError> {
+        let raw = self.get_value().to_string();
+        let date_part = raw.split('T').next().unwrap_or(&raw);
+        parse_date(date_part)
+    }
+
+    /// Parses this property's value as an RFC 6350 date-time and returns it as a [`NaiveDateTime`].
+    pub fn as_datetime(&self) -> Result<NaiveDateTime, VcardError> {
+        let raw = self.get_value().to_string();
+        let (date_part, time_part) = raw.split_once('T').ok_or_else(|| VcardError::PropertyMalformedString(raw.clone()))?;
+        let date = parse_date(date_part)?;
+        let (time, _) = parse_time(time_part)?;
+        Ok(NaiveDateTime::new(date, time))
+    }
+
+    /// Parses this property's value as an RFC 6350 date-time carrying a `Z` or `±HHMM` zone
+    /// offset and returns it as a timezone-aware [`DateTime<FixedOffset>`].
+    pub fn as_datetime_with_timezone(&self) -> Result<DateTime<FixedOffset>, VcardError> {
+        let raw = self.get_value().to_string();
+        let err = || VcardError::PropertyMalformedString(raw.clone());
+        let (date_part, time_part) = raw.split_once('T').ok_or_else(err)?;
+        let date = parse_date(date_part)?;
+        let (time, offset) = parse_time(time_part)?;
+        let offset = offset.ok_or_else(err)?;
+        offset.from_local_datetime(&NaiveDateTime::new(date, time)).single().ok_or_else(err)
+    }
+}
+
+fn parse_date(part: &str) -> Result<NaiveDate, VcardError> {
+    let err = || VcardError::PropertyMalformedString(part.to_string());
+    if part.starts_with('-') {
+        return Err(err());
+    }
+    let digits: String = part.chars().filter(|c| *c != '-').collect();
+    if digits.len() != 8 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(err());
+    }
+    let year = digits[0..4].parse::<i32>().map_err(|_| err())?;
+    let month = digits[4..6].parse::<u32>().map_err(|_| err())?;
+    let day = digits[6..8].parse::<u32>().map_err(|_| err())?;
+    NaiveDate::from_ymd_opt(year, month, day).ok_or_else(err)
+}
+
+fn parse_time(part: &str) -> Result<(NaiveTime, Option<FixedOffset>), VcardError> {
+    let err = || VcardError::PropertyMalformedString(part.to_string());
+    let (time_str, offset) = if let Some(stripped) = part.strip_suffix('Z') {
+        (stripped, Some(FixedOffset::east_opt(0).unwrap()))
+    } else if let Some(pos) = part.rfind(['+', '-']) {
+        let (t, zone) = part.split_at(pos);
+        (t, Some(parse_offset(zone)?))
+    } else {
+        (part, None)
+    };
+
+    if time_str.len() != 6 || !time_str.chars().all(|c| c.is_ascii_digit()) {
+        return Err(err());
+    }
+    let hour = time_str[0..2].parse::<u32>().map_err(|_| err())?;
+    let minute = time_str[2..4].parse::<u32>().map_err(|_| err())?;
+    let second = time_str[4..6].parse::<u32>().map_err(|_| err())?;
+    let time = NaiveTime::from_hms_opt(hour, minute, second).ok_or_else(err)?;
+    Ok((time, offset))
+}
+
+fn parse_offset(part: &str) -> Result<FixedOffset, VcardError> {
+    let err = || VcardError::PropertyMalformedString(part.to_string());
+    if part.len() != 5 {
+        return Err(err());
+    }
+    let sign = if part.starts_with('-') { -1 } else { 1 };
+    let hours = part[1..3].parse::<i32>().map_err(|_| err())?;
+    let minutes = part[3..5].parse::<i32>().map_err(|_| err())?;
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or_else(err)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, Offset, Timelike};
+
+    use crate::vcard::property::types::PropertyType;
+    use crate::vcard::property::Property;
+
+    #[test]
+    pub fn as_date_parses_basic_and_extended_forms() {
+        let basic = Property::try_from((&PropertyType::BDay, "19960422", None)).unwrap();
+        let extended = Property::try_from((&PropertyType::BDay, "1996-04-22", None)).unwrap();
+
+        assert_eq!(basic.as_date().unwrap(), extended.as_date().unwrap());
+        assert_eq!(basic.as_date().unwrap().year(), 1996);
+        assert_eq!(basic.as_date().unwrap().month(), 4);
+        assert_eq!(basic.as_date().unwrap().day(), 22);
+    }
+
+    #[test]
+    pub fn as_date_rejects_truncated_forms() {
+        for value in ["--0422", "--04", "---22"] {
+            let property = Property::try_from((&PropertyType::BDay, value, None)).unwrap();
+            assert!(property.as_date().is_err(), "expected {value} to be rejected");
+        }
+    }
+
+    #[test]
+    pub fn as_datetime_parses_date_and_time() {
+        let property = Property::try_from((&PropertyType::Rev, "19961022T140000", None)).unwrap();
+        let datetime = property.as_datetime().unwrap();
+
+        assert_eq!(datetime.year(), 1996);
+        assert_eq!(datetime.hour(), 14);
+        assert_eq!(datetime.minute(), 0);
+    }
+
+    #[test]
+    pub fn as_datetime_with_timezone_parses_z_and_offset() {
+        let utc = Property::try_from((&PropertyType::Rev, "19961022T140000Z", None)).unwrap();
+        let offset = Property::try_from((&PropertyType::Rev, "19961022T140000-0500", None)).unwrap();
+
+        assert_eq!(utc.as_datetime_with_timezone().unwrap().offset().local_minus_utc(), 0);
+        assert_eq!(offset.as_datetime_with_timezone().unwrap().offset().local_minus_utc(), -5 * 3600);
+    }
+
+    #[test]
+    pub fn as_datetime_with_timezone_requires_an_offset() {
+        let property = Property::try_from((&PropertyType::Rev, "19961022T140000", None)).unwrap();
+        assert!(property.as_datetime_with_timezone().is_err());
+    }
+}
@@ -0,0 +1,78 @@
+//! Base64 media helpers for the binary-bearing property types (`PHOTO`, `LOGO`, `SOUND`, `KEY`).
+//! Understands both the vCard 4.0 `data:` URI value and the vCard 3.0-style
+//! `ENCODING=b;TYPE=...` base64 value.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::vcard::property::types::PropertyType;
+use crate::vcard::property::Property;
+use crate::VcardError;
+
+impl Property {
+    /// Decodes this property's value into its media type (if known) and raw bytes. Recognizes the
+    /// vCard 4.0 `data:` URI value and the vCard 3.0-style value carrying an
+    /// `ENCODING=b`/`ENCODING=BASE64` parameter; any other value (e.g. a plain `PHOTO`/`LOGO` URI)
+    /// is rejected rather than being guessed at by attempting a base64 decode anyway. For a `data:`
+    /// URI the media type is its MIME type; for the 3.0 form it is the `TYPE` parameter's value as
+    /// written (e.g. `JPEG`), not mapped to a MIME type.
+    pub fn media_bytes(&self) -> Result<(Option<String>, Vec<u8>), VcardError> {
+        let raw = self.get_value().to_string();
+        let err = || VcardError::PropertyMalformedString(raw.clone());
+
+        if let Some(rest) = raw.strip_prefix("data:") {
+            let (mime, payload) = rest.split_once(";base64,").ok_or_else(err)?;
+            let bytes = STANDARD.decode(payload).map_err(|_| err())?;
+            let mime = if mime.is_empty() { None } else { Some(mime.to_string()) };
+            return Ok((mime, bytes));
+        }
+
+        let parameters: Vec<String> = self.get_parameters().iter().map(|p| p.to_string()).collect();
+        let is_base64 = parameters.iter().any(|p| matches!(p.to_ascii_uppercase().as_str(), "ENCODING=B" | "ENCODING=BASE64"));
+        if !is_base64 {
+            return Err(err());
+        }
+
+        let mime = parameters.iter().find(|p| p.to_ascii_uppercase().starts_with("TYPE=")).map(|p| p["TYPE=".len()..].to_string());
+        let bytes = STANDARD.decode(&raw).map_err(|_| err())?;
+        Ok((mime, bytes))
+    }
+
+    /// Builds a media property (`PHOTO`, `LOGO`, `SOUND`, `KEY`) from raw bytes, encoding them as a
+    /// vCard 4.0 `data:<mime>;base64,<...>` value. The vCard 3.0 `ENCODING=b;TYPE=...` form is only
+    /// supported for decoding via [`Property::media_bytes`], not for construction.
+    pub fn from_media(property_type: PropertyType, mime: &str, bytes: &[u8]) -> Result<Property, VcardError> {
+        let value = format!("data:{};base64,{}", mime, STANDARD.encode(bytes));
+        Property::try_from((&property_type, value.as_str(), None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vcard::property::types::PropertyType;
+    use crate::vcard::property::Property;
+
+    #[test]
+    pub fn media_round_trip_via_data_uri() {
+        let property = Property::from_media(PropertyType::Photo, "image/jpeg", b"\x01\x02\x03").unwrap();
+        let (mime, bytes) = property.media_bytes().unwrap();
+
+        assert_eq!(mime.as_deref(), Some("image/jpeg"));
+        assert_eq!(bytes, vec![1, 2, 3]);
+    }
+
+    #[test]
+    pub fn media_bytes_rejects_plain_uri_value() {
+        let property = Property::try_from((&PropertyType::Photo, "http://example.com/photo.jpg", None)).unwrap();
+        assert!(property.media_bytes().is_err());
+    }
+
+    #[test]
+    pub fn media_bytes_decodes_vcard3_encoding_b() {
+        let property = Property::try_from(("PHOTO;ENCODING=b;TYPE=JPEG:AQID", None)).unwrap();
+        let (mime, bytes) = property.media_bytes().unwrap();
+
+        assert_eq!(mime.as_deref(), Some("JPEG"));
+        assert_eq!(bytes, vec![1, 2, 3]);
+    }
+}
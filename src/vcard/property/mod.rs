@@ -13,6 +13,23 @@ use crate::VcardError;
 /// Stores the property type as an enum variant.
 pub mod types;
 
+/// Implements `Serialize`/`Deserialize` for [`Property`] behind the `serde` feature.
+#[cfg(feature = "serde")]
+mod serde_support;
+
+/// Adds chrono-backed date/time accessors to [`Property`] behind the `timeconversions` feature.
+#[cfg(feature = "timeconversions")]
+mod datetime;
+
+/// Strongly-typed, lossless views over the values of constrained properties such as `KIND` and `GENDER`.
+pub mod typed;
+
+/// Base64 media helpers for `PHOTO`/`LOGO`/`SOUND`/`KEY` properties.
+mod media;
+
+/// A fluent, validating builder for [`Property`].
+pub mod builder;
+
 /// Stores property data including type, parameter and value. Includes an autogenerated uuid for convenient lookup.
 /// Normally you won't create properties manually, rather you would use the Vcard implementations for [adding](super::Vcard::add_property)
 /// and [updating](super::Vcard::update_property) the property instead, as vcard properties are immutable.
@@ -66,7 +83,7 @@ impl From<PropertyType> for Property {
 impl TryFrom<(&str, Option<Uuid>)> for Property {
     type Error = VcardError;
     fn try_from((str, uuid): (&str, Option<Uuid>)) -> Result<Self, Self::Error> {
-        let str = str.replace('\r', "").replace('\n', "");
+        let str = unfold(&str);
         let (pt, pv, pp) = match str.split_once(':') {
             None => Err(VcardError::PropertyMalformedString(str.to_string())),
             Some((rest, property_values)) => match rest.split_once(';') {
@@ -143,12 +160,57 @@ impl TryFrom<(&PropertyType, &str, Option<Uuid>)> for Property {
 
 impl Display for Property {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if !self.property_parameters.is_empty() {
-            write!(f, "{};{}:{}", self.property_type, self.parameters_to_string(), self.property_value)
+        let line = if !self.property_parameters.is_empty() {
+            format!("{};{}:{}", self.property_type, self.parameters_to_string(), self.property_value)
         } else {
-            write!(f, "{}:{}", self.property_type, self.property_value)
+            format!("{}:{}", self.property_type, self.property_value)
+        };
+        write!(f, "{}", fold(&line))
+    }
+}
+
+/// Maximum number of octets RFC 6350 §3.3 allows on a single physical content line.
+const FOLD_LIMIT: usize = 75;
+
+/// Breaks `line` into RFC 6350 folded continuation lines, never exceeding [`FOLD_LIMIT`] octets per
+/// line and never splitting a multibyte UTF-8 sequence.
+fn fold(line: &str) -> String {
+    if line.len() <= FOLD_LIMIT {
+        return line.to_string();
+    }
+
+    let mut folded = String::with_capacity(line.len() + line.len() / FOLD_LIMIT * 3);
+    let mut segment_start = 0;
+    let mut segment_len = 0;
+    // Every continuation line after the first carries a 1-octet leading space, so its content
+    // budget is one octet smaller than the first line's.
+    let mut limit = FOLD_LIMIT;
+    for (i, ch) in line.char_indices() {
+        let char_len = ch.len_utf8();
+        if segment_len + char_len > limit {
+            folded.push_str(&line[segment_start..i]);
+            folded.push_str("\r\n ");
+            segment_start = i;
+            segment_len = 0;
+            limit = FOLD_LIMIT - 1;
+        }
+        segment_len += char_len;
+    }
+    folded.push_str(&line[segment_start..]);
+    folded
+}
+
+/// Unfolds RFC 6350 continuation lines: a line beginning with a space or tab is joined onto the
+/// previous line, with that one leading whitespace character removed.
+fn unfold(str: &str) -> String {
+    let mut unfolded = String::with_capacity(str.len());
+    for line in str.split(['\r', '\n']).filter(|l| !l.is_empty()) {
+        match line.strip_prefix(' ').or_else(|| line.strip_prefix('\t')) {
+            Some(continuation) => unfolded.push_str(continuation),
+            None => unfolded.push_str(line),
         }
     }
+    unfolded
 }
 
 #[cfg(test)]
@@ -158,7 +220,7 @@ mod tests {
 
     #[test]
     pub fn property_formatting() {
-        let text = "ADR;TYPE=HOME;TYPE=pref:;;1600 Pennsylvania Avenue NW;Washington;DC;20500;United States";
+        let text = "ADR;TYPE=HOME;TYPE=pref:;;1600 Pennsylvania Ave NW;Washington;DC;20500";
         assert_eq!(Property::try_from((text, None)).unwrap().to_string(), text);
 
         assert!(matches!(Property::try_from((&PropertyType::Adr, ";;;;;;", None)), Ok(_)));
@@ -206,4 +268,24 @@ mod tests {
         assert!(matches!(Property::try_from((&PropertyType::Version, "4.0", None)), Ok(_)));
         assert!(matches!(Property::try_from((&PropertyType::Xml, "", None)), Ok(_)));
     }
+
+    #[test]
+    pub fn property_line_folding() {
+        let text = "ADR;TYPE=HOME;TYPE=pref:;;1600 Pennsylvania Avenue NW;Washington;DC;20500;United States";
+        let folded = Property::try_from((text, None)).unwrap().to_string();
+
+        assert_ne!(folded, text);
+        assert!(folded.lines().all(|l| l.len() <= 75));
+        assert_eq!(Property::try_from((folded.as_str(), None)).unwrap().to_string(), folded);
+    }
+
+    #[test]
+    pub fn property_line_folding_multiple_continuations() {
+        let note = "x".repeat(200);
+        let folded = Property::try_from((&PropertyType::Note, note.as_str(), None)).unwrap().to_string();
+
+        assert!(folded.lines().count() >= 3);
+        assert!(folded.lines().all(|l| l.len() <= 75));
+        assert_eq!(Property::try_from((folded.as_str(), None)).unwrap().to_string(), folded);
+    }
 }
@@ -0,0 +1,86 @@
+//! Structured JSON shape for [`Property`]: `{ "type", "uuid", "parameters", "value" }`.
+//! Deserializing re-runs the same validation path as `TryFrom<(&str, Option<Uuid>)>`, so an
+//! invalid value cannot round-trip into a [`Property`] without an error.
+
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use uuid::Uuid;
+
+use crate::vcard::parameter::Parameter;
+use crate::vcard::property::types::PropertyType;
+use crate::vcard::property::Property;
+use crate::vcard::values::Value;
+
+#[derive(Serialize)]
+struct PropertyRef<'a> {
+    #[serde(rename = "type")]
+    property_type: &'a PropertyType,
+    uuid: Uuid,
+    parameters: &'a Vec<Parameter>,
+    value: &'a Value,
+}
+
+#[derive(Deserialize)]
+struct PropertyOwned {
+    #[serde(rename = "type")]
+    property_type: PropertyType,
+    uuid: Uuid,
+    parameters: Vec<Parameter>,
+    value: Value,
+}
+
+impl Serialize for Property {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        PropertyRef {
+            property_type: self.get_type(),
+            uuid: self.get_uuid(),
+            parameters: self.get_parameters(),
+            value: self.get_value(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Property {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let owned = PropertyOwned::deserialize(deserializer)?;
+        let parameters: Vec<String> = owned.parameters.iter().map(Parameter::to_string).collect();
+        let str = if parameters.is_empty() {
+            format!("{}:{}", owned.property_type, owned.value)
+        } else {
+            format!("{};{}:{}", owned.property_type, parameters.join(";"), owned.value)
+        };
+        Property::try_from((str.as_str(), Some(owned.uuid))).map_err(DeError::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vcard::property::types::PropertyType;
+    use crate::vcard::property::Property;
+
+    #[test]
+    pub fn property_serde_round_trip() {
+        let property = Property::try_from((&PropertyType::Fn, "John Doe", None)).unwrap();
+
+        let json = serde_json::to_string(&property).unwrap();
+        let restored: Property = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.to_string(), property.to_string());
+        assert_eq!(restored.get_uuid(), property.get_uuid());
+    }
+
+    #[test]
+    pub fn property_serde_rejects_invalid_value() {
+        // GENDER allows at most 2 `;`-separated components (sex, identity); a 3rd is invalid and
+        // must fail validation on deserialize rather than silently constructing a bad Property.
+        let json = r#"{"type":"Gender","uuid":"00000000-0000-0000-0000-000000000000","parameters":[],"value":{"raw":"M;identity;extra"}}"#;
+        assert!(serde_json::from_str::<Property>(json).is_err());
+    }
+}
@@ -0,0 +1,154 @@
+//! Typed views over the values of constrained properties (`KIND`, `GENDER`). [`Kind`] carries
+//! the recognized variant when it can, or falls back to the raw string for unknown/x-name values.
+//! In a [`Gender`], a sex component other than `M`, `F`, `O`, `N` or `U` reads as `None`; the raw
+//! text is not kept in the view. The property itself is never modified, so it stays lossless on
+//! re-serialization.
+
+use std::fmt::{Display, Formatter};
+
+use crate::vcard::property::types::PropertyType;
+use crate::vcard::property::Property;
+
+/// The `KIND` property's value (RFC 6350 §6.1.4).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Kind {
+    Individual,
+    Group,
+    Org,
+    Location,
+    /// An unrecognized or x-name value, preserved verbatim.
+    Other(String),
+}
+
+impl Display for Kind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Kind::Individual => write!(f, "individual"),
+            Kind::Group => write!(f, "group"),
+            Kind::Org => write!(f, "org"),
+            Kind::Location => write!(f, "location"),
+            Kind::Other(raw) => write!(f, "{}", raw),
+        }
+    }
+}
+
+impl From<&str> for Kind {
+    fn from(raw: &str) -> Self {
+        match raw.to_ascii_lowercase().as_str() {
+            "individual" => Kind::Individual,
+            "group" => Kind::Group,
+            "org" => Kind::Org,
+            "location" => Kind::Location,
+            _ => Kind::Other(raw.to_string()),
+        }
+    }
+}
+
+/// The `SEX` component of a `GENDER` property's value (RFC 6350 §6.2.7).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sex {
+    Male,
+    Female,
+    Other,
+    None,
+    Unknown,
+}
+
+impl Display for Sex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Sex::Male => "M",
+            Sex::Female => "F",
+            Sex::Other => "O",
+            Sex::None => "N",
+            Sex::Unknown => "U",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl Sex {
+    fn from_str(raw: &str) -> Option<Sex> {
+        match raw {
+            "M" => Some(Sex::Male),
+            "F" => Some(Sex::Female),
+            "O" => Some(Sex::Other),
+            "N" => Some(Sex::None),
+            "U" => Some(Sex::Unknown),
+            _ => None,
+        }
+    }
+}
+
+/// The `GENDER` property's value: an optional [`Sex`] and an optional free-text gender identity.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct Gender {
+    pub sex: Option<Sex>,
+    pub identity: Option<String>,
+}
+
+impl Property {
+    /// Returns the `KIND` value as a [`Kind`], or `None` if this isn't a `KIND` property.
+    pub fn as_kind(&self) -> Option<Kind> {
+        if !matches!(self.get_type(), PropertyType::Kind) {
+            return None;
+        }
+        Some(Kind::from(self.get_value().to_string().as_str()))
+    }
+
+    /// Returns the `GENDER` value as a [`Gender`], or `None` if this isn't a `GENDER` property.
+    pub fn as_gender(&self) -> Option<Gender> {
+        if !matches!(self.get_type(), PropertyType::Gender) {
+            return None;
+        }
+        let raw = self.get_value().to_string();
+        let mut parts = raw.splitn(2, ';');
+        let sex = parts.next().filter(|s| !s.is_empty()).and_then(Sex::from_str);
+        let identity = parts.next().filter(|s| !s.is_empty()).map(str::to_string);
+        Some(Gender { sex, identity })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vcard::property::types::PropertyType;
+    use crate::vcard::property::typed::{Kind, Sex};
+    use crate::vcard::property::Property;
+
+    #[test]
+    pub fn as_kind_recognizes_known_variants() {
+        let property = Property::try_from((&PropertyType::Kind, "individual", None)).unwrap();
+        assert_eq!(property.as_kind(), Some(Kind::Individual));
+    }
+
+    #[test]
+    pub fn as_kind_falls_back_to_raw_for_unknown_values() {
+        let property = Property::try_from((&PropertyType::Kind, "x-department", None)).unwrap();
+        assert_eq!(property.as_kind(), Some(Kind::Other("x-department".to_string())));
+        assert_eq!(property.as_kind().unwrap().to_string(), "x-department");
+    }
+
+    #[test]
+    pub fn as_kind_is_none_for_other_property_types() {
+        let property = Property::try_from((&PropertyType::Fn, "John Doe", None)).unwrap();
+        assert_eq!(property.as_kind(), None);
+    }
+
+    #[test]
+    pub fn as_gender_parses_sex_and_identity() {
+        let property = Property::try_from((&PropertyType::Gender, "F;transgender woman", None)).unwrap();
+        let gender = property.as_gender().unwrap();
+
+        assert_eq!(gender.sex, Some(Sex::Female));
+        assert_eq!(gender.identity.as_deref(), Some("transgender woman"));
+    }
+
+    #[test]
+    pub fn as_gender_handles_missing_components() {
+        let property = Property::try_from((&PropertyType::Gender, "", None)).unwrap();
+        let gender = property.as_gender().unwrap();
+
+        assert_eq!(gender.sex, None);
+        assert_eq!(gender.identity, None);
+    }
+}
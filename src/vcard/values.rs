@@ -0,0 +1,80 @@
+//! The value portion of a property (the text after the first `:`).
+
+use std::fmt::{Display, Formatter};
+
+use crate::vcard::parameter::Parameter;
+use crate::vcard::property::types::PropertyType;
+use crate::VcardError;
+
+/// A property's value, stored as RFC text. Compound properties (e.g. `ADR`, `N`) are validated
+/// against their maximum `;`-separated component count; simple properties accept any text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Value {
+    raw: String,
+}
+
+/// The maximum number of `;`-separated components a compound property's value may have.
+fn max_components(property_type: &PropertyType) -> Option<usize> {
+    match property_type {
+        PropertyType::Adr => Some(7),
+        PropertyType::N => Some(5),
+        PropertyType::Gender => Some(2),
+        PropertyType::ClientPidMap => Some(2),
+        _ => None,
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl From<&PropertyType> for Value {
+    fn from(property_type: &PropertyType) -> Self {
+        let raw = match property_type {
+            PropertyType::Version => "4.0",
+            _ => "",
+        };
+        Value { raw: raw.to_string() }
+    }
+}
+
+impl TryFrom<(&PropertyType, &Vec<Parameter>, &str)> for Value {
+    type Error = VcardError;
+    fn try_from((property_type, _parameters, raw): (&PropertyType, &Vec<Parameter>, &str)) -> Result<Self, Self::Error> {
+        if let Some(max) = max_components(property_type) {
+            if raw.split(';').count() > max {
+                return Err(VcardError::PropertyMalformedString(raw.to_string()));
+            }
+        }
+        Ok(Value { raw: raw.to_string() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vcard::parameter::Parameter;
+    use crate::vcard::property::types::PropertyType;
+    use crate::vcard::values::Value;
+
+    #[test]
+    pub fn value_accepts_compound_properties_within_their_component_limit() {
+        let parameters: Vec<Parameter> = Vec::new();
+        assert!(Value::try_from((&PropertyType::Adr, &parameters, ";;;;;;")).is_ok());
+        assert!(Value::try_from((&PropertyType::Gender, &parameters, "M;M")).is_ok());
+    }
+
+    #[test]
+    pub fn value_rejects_compound_properties_past_their_component_limit() {
+        let parameters: Vec<Parameter> = Vec::new();
+        assert!(Value::try_from((&PropertyType::Gender, &parameters, "M;identity;extra")).is_err());
+    }
+
+    #[test]
+    pub fn value_from_property_type_gives_version_a_default() {
+        assert_eq!(Value::from(&PropertyType::Version).to_string(), "4.0");
+        assert_eq!(Value::from(&PropertyType::Fn).to_string(), "");
+    }
+}